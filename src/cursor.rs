@@ -0,0 +1,167 @@
+//! A small, allocation-free cursor for sequentially reading and writing endian-specific
+//! values from and to a byte slice.
+//!
+//! This fills the gap between "data is already overlaid as a `#[repr(C)]` struct", which the
+//! rest of this crate is built around, and "data arrives as a stream of fields whose widths
+//! and byte orders are only known at runtime", where a struct overlay cannot be used.
+//!
+//! [`Reader`] and [`Writer`] are `no_std` and never allocate; every method does its own
+//! bounds checking and reports failure instead of panicking.
+
+/// A cursor for sequentially reading big or little endian values out of a byte slice.
+///
+/// Every `read_*` method returns `None`, leaving the cursor position unchanged, if fewer
+/// bytes than required remain.
+pub struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Create a new reader over `buf`, starting at offset `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(buf: &'a [u8]) -> Self {
+        Reader { buf, pos: 0 }
+    }
+
+    /// The number of bytes that have not yet been read.
+    #[inline]
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Whether every byte of the underlying slice has been read.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.pos == self.buf.len()
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if self.remaining() < N {
+            return None;
+        }
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&self.buf[self.pos..self.pos + N]);
+        self.pos += N;
+        Some(bytes)
+    }
+
+    /// Read a single byte.
+    #[inline]
+    pub fn read_u8(&mut self) -> Option<u8> {
+        let [byte] = self.read_array::<1>()?;
+        Some(byte)
+    }
+}
+
+macro_rules! read_methods {
+    ($read_be:ident, $read_le:ident, $type:ident, $bytes:expr) => {
+        impl<'a> Reader<'a> {
+            #[doc = concat!("Read a big endian `", stringify!($type), "`.")]
+            #[inline]
+            pub fn $read_be(&mut self) -> Option<$type> {
+                self.read_array::<$bytes>().map($type::from_be_bytes)
+            }
+
+            #[doc = concat!("Read a little endian `", stringify!($type), "`.")]
+            #[inline]
+            pub fn $read_le(&mut self) -> Option<$type> {
+                self.read_array::<$bytes>().map($type::from_le_bytes)
+            }
+        }
+    };
+}
+
+read_methods!(read_u16be, read_u16le, u16, 2);
+read_methods!(read_u32be, read_u32le, u32, 4);
+read_methods!(read_u64be, read_u64le, u64, 8);
+read_methods!(read_u128be, read_u128le, u128, 16);
+
+read_methods!(read_i16be, read_i16le, i16, 2);
+read_methods!(read_i32be, read_i32le, i32, 4);
+read_methods!(read_i64be, read_i64le, i64, 8);
+read_methods!(read_i128be, read_i128le, i128, 16);
+
+read_methods!(read_f32be, read_f32le, f32, 4);
+read_methods!(read_f64be, read_f64le, f64, 8);
+
+/// A cursor for sequentially writing big or little endian values into a byte slice.
+///
+/// Every `write_*` method returns `false`, leaving the underlying bytes unchanged, if fewer
+/// bytes than required remain.
+pub struct Writer<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> Writer<'a> {
+    /// Create a new writer over `buf`, starting at offset `0`.
+    #[inline]
+    #[must_use]
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Writer { buf, pos: 0 }
+    }
+
+    /// The number of bytes that are still free to be written to.
+    #[inline]
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Whether every byte of the underlying slice has been written to.
+    #[inline]
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.pos == self.buf.len()
+    }
+
+    fn write_array<const N: usize>(&mut self, bytes: [u8; N]) -> bool {
+        if self.remaining() < N {
+            return false;
+        }
+        self.buf[self.pos..self.pos + N].copy_from_slice(&bytes);
+        self.pos += N;
+        true
+    }
+
+    /// Write a single byte. Returns `false` if no space remains.
+    #[inline]
+    pub fn write_u8(&mut self, value: u8) -> bool {
+        self.write_array([value])
+    }
+}
+
+macro_rules! write_methods {
+    ($write_be:ident, $write_le:ident, $type:ident, $bytes:expr) => {
+        impl<'a> Writer<'a> {
+            #[doc = concat!("Write a big endian `", stringify!($type), "`. Returns `false` if no space remains.")]
+            #[inline]
+            pub fn $write_be(&mut self, value: $type) -> bool {
+                self.write_array(value.to_be_bytes())
+            }
+
+            #[doc = concat!("Write a little endian `", stringify!($type), "`. Returns `false` if no space remains.")]
+            #[inline]
+            pub fn $write_le(&mut self, value: $type) -> bool {
+                self.write_array(value.to_le_bytes())
+            }
+        }
+    };
+}
+
+write_methods!(write_u16be, write_u16le, u16, 2);
+write_methods!(write_u32be, write_u32le, u32, 4);
+write_methods!(write_u64be, write_u64le, u64, 8);
+write_methods!(write_u128be, write_u128le, u128, 16);
+
+write_methods!(write_i16be, write_i16le, i16, 2);
+write_methods!(write_i32be, write_i32le, i32, 4);
+write_methods!(write_i64be, write_i64le, i64, 8);
+write_methods!(write_i128be, write_i128le, i128, 16);
+
+write_methods!(write_f32be, write_f32le, f32, 4);
+write_methods!(write_f64be, write_f64le, f64, 8);
@@ -17,6 +17,20 @@
 //! But please note that a operation with a constant may be more efficient with `get`ting the value
 //! instead of wrapping the constant with `new`, though depending on the constant and cpu.
 //!
+//! All types are stored as a byte array in their target endianness, which gives them an
+//! alignment of 1. This means they can be placed at any offset inside a `#[repr(C)]` struct
+//! and the struct can be safely overlaid on top of a `&[u8]` coming from a wire format or a
+//! memory mapped file, even when a field does not start at an offset matching its native
+//! alignment.
+//!
+//! The integer types are generic over a [`ByteOrder`] marker ([`U32<BigEndian>`](U32), ...), with
+//! `u32be`, `u32le`, etc. provided as type aliases for the common cases. Writing code generically
+//! over `ByteOrder` makes it possible to share a single definition across both endiannesses.
+//!
+//! When the data does not already sit in a fixed struct layout but arrives as a stream of
+//! fields, the [`cursor`] module provides [`cursor::Reader`] and [`cursor::Writer`] to pull
+//! values out of, and push values into, a `&[u8]`/`&mut [u8]` sequentially.
+//!
 //! There are several similar libraries, but they all differ in a some points.
 //!
 //! Some alternatives:
@@ -24,6 +38,7 @@
 //! - [byteorder](https://crates.io/crates/byteorder)
 //! - [endian](https://crates.io/crates/endian)
 //! - [simple_endian](https://crates.io/crates/simple_endian)
+//! - [zerocopy](https://crates.io/crates/zerocopy)
 //!
 //! # Examples
 //!
@@ -72,310 +87,676 @@
 //!
 //! (`+0 == -0` float: equal, bits: different; `nan == nan` float: different, bits: equal)
 
+use core::cmp::Ordering;
+use core::marker::PhantomData;
 use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
 
+pub mod cursor;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Exposes the per-width byte-swap primitive needed to implement [`ByteOrder`] generically.
+///
+/// This trait is an implementation detail of [`ByteOrder`] and is not meant to be implemented
+/// outside of this crate.
+#[doc(hidden)]
+pub trait Swap: Copy {
+    /// Swap the bytes of `self`, if necessary.
+    #[must_use]
+    fn to_be(self) -> Self;
+    /// Swap the bytes of `self`, if necessary.
+    #[must_use]
+    fn to_le(self) -> Self;
+}
+
+macro_rules! impl_swap {
+    ($($type:ident),* $(,)?) => {
+        $(
+            impl Swap for $type {
+                #[inline]
+                fn to_be(self) -> Self {
+                    $type::to_be(self)
+                }
+                #[inline]
+                fn to_le(self) -> Self {
+                    $type::to_le(self)
+                }
+            }
+        )*
+    };
+}
+impl_swap!(u16, u32, u64, u128, i16, i32, i64, i128);
+
+/// Reverse the order of the bytes in an array.
+///
+/// This is the `const fn`-compatible equivalent of swapping bytes through [`ByteOrder::to`]/
+/// [`ByteOrder::from`]: those are ordinary trait methods and cannot be called from a `const fn`
+/// on stable Rust, so the `const fn` `new` and `get` methods dispatch on [`ByteOrder::REVERSE`]
+/// and call this free function instead.
+const fn reverse_bytes<const N: usize>(mut bytes: [u8; N]) -> [u8; N] {
+    let mut i = 0;
+    while i < N / 2 {
+        let j = N - 1 - i;
+        let tmp = bytes[i];
+        bytes[i] = bytes[j];
+        bytes[j] = tmp;
+        i += 1;
+    }
+    bytes
+}
+
+/// A marker type selecting the byte order of the generic integer and floating-point types.
+///
+/// This trait is sealed: [`BigEndian`] and [`LittleEndian`] are the only implementors.
+pub trait ByteOrder: sealed::Sealed + Clone + Copy {
+    /// The byte order obtained by reversing this one.
+    type Opposite: ByteOrder<Opposite = Self>;
+
+    /// `true` if this byte order is the reverse of the target's native byte order.
+    ///
+    /// This is a `const` twin of [`to`](Self::to)/[`from`](Self::from): it lets `const fn`
+    /// constructors decide whether to reverse bytes without calling a (non-const) trait method.
+    #[doc(hidden)]
+    const REVERSE: bool;
+
+    /// Convert a native value into this byte order.
+    #[doc(hidden)]
+    fn to<T: Swap>(native: T) -> T;
+
+    /// Convert a value in this byte order into native byte order.
+    #[doc(hidden)]
+    fn from<T: Swap>(stored: T) -> T;
+}
+
+/// Marker type selecting big endian byte order.
+#[derive(Clone, Copy)]
+pub struct BigEndian;
+
+impl sealed::Sealed for BigEndian {}
+
+impl ByteOrder for BigEndian {
+    type Opposite = LittleEndian;
+
+    const REVERSE: bool = cfg!(target_endian = "little");
+
+    #[inline]
+    fn to<T: Swap>(native: T) -> T {
+        native.to_be()
+    }
+
+    #[inline]
+    fn from<T: Swap>(stored: T) -> T {
+        stored.to_be()
+    }
+}
+
+/// Marker type selecting little endian byte order.
+#[derive(Clone, Copy)]
+pub struct LittleEndian;
+
+impl sealed::Sealed for LittleEndian {}
+
+impl ByteOrder for LittleEndian {
+    type Opposite = BigEndian;
+
+    const REVERSE: bool = cfg!(target_endian = "big");
+
+    #[inline]
+    fn to<T: Swap>(native: T) -> T {
+        native.to_le()
+    }
+
+    #[inline]
+    fn from<T: Swap>(stored: T) -> T {
+        stored.to_le()
+    }
+}
+
 macro_rules! create_int {
-    ($name:ident, $type:ident, $from:ident, $to:ident, $bytes:expr, $doc:literal) => {
+    (@ord unsigned $name:ident) => {
+        // Big endian stores its bytes most-significant-first, which is also lexicographic
+        // byte order, so for an unsigned integer comparing the stored bytes directly gives
+        // the same result as comparing the numeric values, without any conversion.
+        impl PartialOrd for $name<BigEndian> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name<BigEndian> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl PartialOrd for $name<LittleEndian> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name<LittleEndian> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+    };
+    (@ord signed $name:ident) => {
+        impl<O: ByteOrder> PartialOrd for $name<O> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<O: ByteOrder> Ord for $name<O> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+    };
+    ($name:ident, $type:ident, $bytes:expr, $ord:ident, $doc:literal) => {
         #[doc = $doc]
         #[allow(non_camel_case_types)]
-        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[derive(Clone, Copy)]
         #[repr(transparent)]
-        pub struct $name($type);
-        impl $name {
+        pub struct $name<O: ByteOrder>([u8; $bytes], PhantomData<O>);
+
+        impl<O: ByteOrder> PartialEq for $name<O> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<O: ByteOrder> Eq for $name<O> {}
+
+        impl<O: ByteOrder> $name<O> {
             /// Convert a native byte order integer into an endianness specific integer.
             #[inline]
             #[must_use]
-            pub const fn new(value: $type) -> $name {
-                $name(value.$to())
+            pub const fn new(value: $type) -> Self {
+                let bytes = value.to_ne_bytes();
+                Self(if O::REVERSE { reverse_bytes(bytes) } else { bytes }, PhantomData)
             }
 
             /// Convert the endianness specific integer into native byte order.
             #[inline]
             #[must_use]
             pub const fn get(&self) -> $type {
-                $type::$from(self.0)
+                let bytes = if O::REVERSE { reverse_bytes(self.0) } else { self.0 };
+                $type::from_ne_bytes(bytes)
             }
 
             /// Convert a native byte order integer and store it in this endianness specific integer.
             #[inline]
             pub fn set(&mut self, value: $type) {
-                self.0 = value.$to();
+                self.0 = O::to(value).to_ne_bytes();
             }
 
             /// Check if the value is zero.
             #[inline]
             #[must_use]
             pub const fn is_zero(&self) -> bool {
-                // Safety: big and little endian encode a 0 the same way
-                self.0 == 0
+                // Safety: a value is zero if and only if all of its bytes are zero,
+                // regardless of endianness
+                let mut i = 0;
+                while i < $bytes {
+                    if self.0[i] != 0 {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
             }
 
             /// Creates an integer value from its representation as a byte array in big endian.
             #[inline]
             #[must_use]
-            pub const fn from_be_bytes(bytes: [u8; $bytes]) -> $name {
-                $name($type::from_be_bytes(bytes).$to())
+            pub const fn from_be_bytes(bytes: [u8; $bytes]) -> Self {
+                Self::new($type::from_be_bytes(bytes))
+            }
+
+            /// Creates an integer value from its representation as a byte array in little endian.
+            #[inline]
+            #[must_use]
+            pub const fn from_le_bytes(bytes: [u8; $bytes]) -> Self {
+                Self::new($type::from_le_bytes(bytes))
+            }
+
+            /// Creates an integer value from its representation as a byte array in native endian.
+            #[inline]
+            #[must_use]
+            pub const fn from_ne_bytes(bytes: [u8; $bytes]) -> Self {
+                Self::new($type::from_ne_bytes(bytes))
+            }
+
+            /// Returns the memory representation of this integer as a byte array in big endian.
+            #[inline]
+            #[must_use]
+            pub const fn to_be_bytes(self) -> [u8; $bytes] {
+                self.get().to_be_bytes()
+            }
+
+            /// Returns the memory representation of this integer as a byte array in little endian.
+            #[inline]
+            #[must_use]
+            pub const fn to_le_bytes(self) -> [u8; $bytes] {
+                self.get().to_le_bytes()
+            }
+
+            /// Returns the memory representation of this integer as a byte array in native endian.
+            #[inline]
+            #[must_use]
+            pub const fn to_ne_bytes(self) -> [u8; $bytes] {
+                self.get().to_ne_bytes()
+            }
+
+            /// Reinterpret the stored bytes as the opposite byte order, without changing them.
+            ///
+            /// This is a zero cost operation, the numeric value changes because the bytes are
+            /// now read in the opposite order. Use this to re-label a buffer whose endianness
+            /// was misidentified.
+            #[inline]
+            #[must_use]
+            pub fn swap_bytes(self) -> $name<O::Opposite> {
+                $name(self.0, PhantomData)
+            }
+
+            /// Convert to the opposite byte order, preserving the numeric value.
+            ///
+            /// Unlike [`swap_bytes`](Self::swap_bytes), the stored bytes are reversed so that
+            /// `self.get() == self.to_opposite_endian().get()`.
+            #[inline]
+            #[must_use]
+            pub fn to_opposite_endian(self) -> $name<O::Opposite> {
+                $name::<O::Opposite>::new(self.get())
             }
         }
 
-        impl BitAnd for $name {
+        impl<O: ByteOrder> BitAnd for $name<O> {
             type Output = Self;
             #[inline]
             fn bitand(self, rhs: Self) -> Self::Output {
-                Self(self.0 & rhs.0)
+                let mut result = [0u8; $bytes];
+                let mut i = 0;
+                while i < $bytes {
+                    result[i] = self.0[i] & rhs.0[i];
+                    i += 1;
+                }
+                Self(result, PhantomData)
             }
         }
 
-        impl BitAndAssign for $name {
+        impl<O: ByteOrder> BitAndAssign for $name<O> {
             #[inline]
             fn bitand_assign(&mut self, rhs: Self) {
-                self.0 &= rhs.0;
+                *self = *self & rhs;
             }
         }
 
-        impl BitOr for $name {
+        impl<O: ByteOrder> BitOr for $name<O> {
             type Output = Self;
             #[inline]
             fn bitor(self, rhs: Self) -> Self::Output {
-                Self(self.0 | rhs.0)
+                let mut result = [0u8; $bytes];
+                let mut i = 0;
+                while i < $bytes {
+                    result[i] = self.0[i] | rhs.0[i];
+                    i += 1;
+                }
+                Self(result, PhantomData)
             }
         }
 
-        impl BitOrAssign for $name {
+        impl<O: ByteOrder> BitOrAssign for $name<O> {
             #[inline]
             fn bitor_assign(&mut self, rhs: Self) {
-                self.0 |= rhs.0;
+                *self = *self | rhs;
             }
         }
 
-        impl BitXor for $name {
+        impl<O: ByteOrder> BitXor for $name<O> {
             type Output = Self;
             #[inline]
             fn bitxor(self, rhs: Self) -> Self::Output {
-                Self(self.0 ^ rhs.0)
+                let mut result = [0u8; $bytes];
+                let mut i = 0;
+                while i < $bytes {
+                    result[i] = self.0[i] ^ rhs.0[i];
+                    i += 1;
+                }
+                Self(result, PhantomData)
             }
         }
 
-        impl BitXorAssign for $name {
+        impl<O: ByteOrder> BitXorAssign for $name<O> {
             #[inline]
             fn bitxor_assign(&mut self, rhs: Self) {
-                self.0 ^= rhs.0;
+                *self = *self ^ rhs;
             }
         }
 
-        impl Not for $name {
+        impl<O: ByteOrder> Not for $name<O> {
             type Output = Self;
             #[inline]
             fn not(self) -> Self::Output {
-                Self(!self.0)
+                let mut result = [0u8; $bytes];
+                let mut i = 0;
+                while i < $bytes {
+                    result[i] = !self.0[i];
+                    i += 1;
+                }
+                Self(result, PhantomData)
             }
         }
+
+        create_int!(@ord $ord $name);
     };
 }
 
 create_int!(
-    u16le,
-    u16,
-    from_le,
-    to_le,
-    2,
-    "The 16-bit little endian unsigned integer type."
-);
-create_int!(
-    u32le,
-    u32,
-    from_le,
-    to_le,
-    4,
-    "The 32-bit little endian unsigned integer type."
-);
-create_int!(
-    u64le,
-    u64,
-    from_le,
-    to_le,
-    8,
-    "The 64-bit little endian unsigned integer type."
-);
-create_int!(
-    u128le,
-    u128,
-    from_le,
-    to_le,
-    16,
-    "The 128-bit little endian unsigned integer type."
-);
-
-create_int!(
-    u16be,
+    U16,
     u16,
-    from_be,
-    to_be,
     2,
-    "The 16-bit big endian unsigned integer type."
+    unsigned,
+    "The 16-bit unsigned integer type, generic over its byte order."
 );
 create_int!(
-    u32be,
+    U32,
     u32,
-    from_be,
-    to_be,
     4,
-    "The 32-bit big endian unsigned integer type."
+    unsigned,
+    "The 32-bit unsigned integer type, generic over its byte order."
 );
 create_int!(
-    u64be,
+    U64,
     u64,
-    from_be,
-    to_be,
     8,
-    "The 64-bit big endian unsigned integer type."
+    unsigned,
+    "The 64-bit unsigned integer type, generic over its byte order."
 );
 create_int!(
-    u128be,
+    U128,
     u128,
-    from_be,
-    to_be,
     16,
-    "The 128-bit big endian unsigned integer type."
+    unsigned,
+    "The 128-bit unsigned integer type, generic over its byte order."
 );
 
 create_int!(
-    i16le,
+    I16,
     i16,
-    from_le,
-    to_le,
     2,
-    "The 16-bit little endian signed integer type."
+    signed,
+    "The 16-bit signed integer type, generic over its byte order."
 );
 create_int!(
-    i32le,
+    I32,
     i32,
-    from_le,
-    to_le,
     4,
-    "The 32-bit little endian signed integer type."
+    signed,
+    "The 32-bit signed integer type, generic over its byte order."
 );
 create_int!(
-    i64le,
+    I64,
     i64,
-    from_le,
-    to_le,
     8,
-    "The 64-bit little endian signed integer type."
+    signed,
+    "The 64-bit signed integer type, generic over its byte order."
 );
 create_int!(
-    i128le,
+    I128,
     i128,
-    from_le,
-    to_le,
     16,
-    "The 128-bit little endian signed integer type."
+    signed,
+    "The 128-bit signed integer type, generic over its byte order."
 );
 
-create_int!(
-    i16be,
-    i16,
-    from_be,
-    to_be,
-    2,
-    "The 16-bit big endian signed integer type."
-);
-create_int!(
-    i32be,
-    i32,
-    from_be,
-    to_be,
-    4,
-    "The 32-bit big endian signed integer type."
-);
-create_int!(
-    i64be,
-    i64,
-    from_be,
-    to_be,
-    8,
-    "The 64-bit big endian signed integer type."
-);
-create_int!(
-    i128be,
-    i128,
-    from_be,
-    to_be,
-    16,
-    "The 128-bit big endian signed integer type."
-);
+macro_rules! create_int_alias {
+    ($alias:ident, $generic:ident, $order:ident, $doc:literal) => {
+        #[doc = $doc]
+        #[allow(non_camel_case_types)]
+        pub type $alias = $generic<$order>;
+    };
+}
+
+create_int_alias!(u16le, U16, LittleEndian, "The 16-bit little endian unsigned integer type.");
+create_int_alias!(u32le, U32, LittleEndian, "The 32-bit little endian unsigned integer type.");
+create_int_alias!(u64le, U64, LittleEndian, "The 64-bit little endian unsigned integer type.");
+create_int_alias!(u128le, U128, LittleEndian, "The 128-bit little endian unsigned integer type.");
+
+create_int_alias!(u16be, U16, BigEndian, "The 16-bit big endian unsigned integer type.");
+create_int_alias!(u32be, U32, BigEndian, "The 32-bit big endian unsigned integer type.");
+create_int_alias!(u64be, U64, BigEndian, "The 64-bit big endian unsigned integer type.");
+create_int_alias!(u128be, U128, BigEndian, "The 128-bit big endian unsigned integer type.");
+
+create_int_alias!(i16le, I16, LittleEndian, "The 16-bit little endian signed integer type.");
+create_int_alias!(i32le, I32, LittleEndian, "The 32-bit little endian signed integer type.");
+create_int_alias!(i64le, I64, LittleEndian, "The 64-bit little endian signed integer type.");
+create_int_alias!(i128le, I128, LittleEndian, "The 128-bit little endian signed integer type.");
+
+create_int_alias!(i16be, I16, BigEndian, "The 16-bit big endian signed integer type.");
+create_int_alias!(i32be, I32, BigEndian, "The 32-bit big endian signed integer type.");
+create_int_alias!(i64be, I64, BigEndian, "The 64-bit big endian signed integer type.");
+create_int_alias!(i128be, I128, BigEndian, "The 128-bit big endian signed integer type.");
 
 macro_rules! create_float {
-    ($name:ident, $float_type:ident, $int_type:ident, $from:ident, $to:ident, $bytes:expr, $doc:literal) => {
+    ($name:ident, $float_type:ident, $int_type:ident, $bytes:expr, $doc:literal) => {
         #[doc = $doc]
         #[allow(non_camel_case_types)]
         #[derive(Clone, Copy)]
         #[repr(transparent)]
-        pub struct $name($int_type);
-        impl $name {
+        pub struct $name<O: ByteOrder>([u8; $bytes], PhantomData<O>);
+
+        impl<O: ByteOrder> $name<O> {
             /// Convert the endianness specific float into native byte order.
             #[inline]
             #[must_use]
             pub const fn get(&self) -> $float_type {
-                $float_type::from_bits($int_type::$from(self.0))
+                let bytes = if O::REVERSE { reverse_bytes(self.0) } else { self.0 };
+                $float_type::from_bits($int_type::from_ne_bytes(bytes))
             }
 
             /// Convert a native byte order float and store it in this endianness specific float.
             #[inline]
             pub fn set(&mut self, value: $float_type) {
-                self.0 = value.to_bits().$to();
+                self.0 = O::to(value.to_bits()).to_ne_bytes();
             }
 
             /// Convert a native byte order float into an endianness specific float.
             #[inline]
             #[must_use]
-            pub const fn new(value: $float_type) -> $name {
-                Self($int_type::$from(value.to_bits()))
+            pub const fn new(value: $float_type) -> Self {
+                let bytes = value.to_bits().to_ne_bytes();
+                Self(if O::REVERSE { reverse_bytes(bytes) } else { bytes }, PhantomData)
             }
 
             /// Creates an float value from its representation as a byte array in big endian.
             #[inline]
             #[must_use]
-            pub const fn from_be_bytes(bytes: [u8; $bytes]) -> $name {
-                $name($int_type::from_be_bytes(bytes).$to())
+            pub const fn from_be_bytes(bytes: [u8; $bytes]) -> Self {
+                Self::new($float_type::from_bits($int_type::from_be_bytes(bytes)))
+            }
+
+            /// Creates an float value from its representation as a byte array in little endian.
+            #[inline]
+            #[must_use]
+            pub const fn from_le_bytes(bytes: [u8; $bytes]) -> Self {
+                Self::new($float_type::from_bits($int_type::from_le_bytes(bytes)))
+            }
+
+            /// Creates an float value from its representation as a byte array in native endian.
+            #[inline]
+            #[must_use]
+            pub const fn from_ne_bytes(bytes: [u8; $bytes]) -> Self {
+                Self::new($float_type::from_bits($int_type::from_ne_bytes(bytes)))
+            }
+
+            /// Returns the memory representation of this float as a byte array in big endian.
+            #[inline]
+            #[must_use]
+            pub const fn to_be_bytes(self) -> [u8; $bytes] {
+                self.get().to_bits().to_be_bytes()
+            }
+
+            /// Returns the memory representation of this float as a byte array in little endian.
+            #[inline]
+            #[must_use]
+            pub const fn to_le_bytes(self) -> [u8; $bytes] {
+                self.get().to_bits().to_le_bytes()
+            }
+
+            /// Returns the memory representation of this float as a byte array in native endian.
+            #[inline]
+            #[must_use]
+            pub const fn to_ne_bytes(self) -> [u8; $bytes] {
+                self.get().to_bits().to_ne_bytes()
+            }
+
+            /// Reinterpret the stored bytes as the opposite byte order, without changing them.
+            ///
+            /// This is a zero cost operation, the numeric value changes because the bytes are
+            /// now read in the opposite order. Use this to re-label a buffer whose endianness
+            /// was misidentified.
+            #[inline]
+            #[must_use]
+            pub fn swap_bytes(self) -> $name<O::Opposite> {
+                $name(self.0, PhantomData)
+            }
+
+            /// Convert to the opposite byte order, preserving the numeric value.
+            ///
+            /// Unlike [`swap_bytes`](Self::swap_bytes), the stored bytes are reversed so that
+            /// `self.get() == self.to_opposite_endian().get()`.
+            #[inline]
+            #[must_use]
+            pub fn to_opposite_endian(self) -> $name<O::Opposite> {
+                $name::<O::Opposite>::new(self.get())
             }
         }
     };
 }
 
 create_float!(
-    f32be,
+    F32,
     f32,
     u32,
-    from_be,
-    to_be,
     4,
-    "The 32-bit big endian floating-point type."
+    "The 32-bit floating-point type, generic over its byte order."
 );
 create_float!(
-    f64be,
+    F64,
     f64,
     u64,
-    from_be,
-    to_be,
     8,
-    "The 64-bit big endian floating-point type."
-);
-create_float!(
-    f32le,
-    f32,
-    u32,
-    from_le,
-    to_le,
-    4,
-    "The 32-bit little endian floating-point type."
-);
-create_float!(
-    f64le,
-    f64,
-    u64,
-    from_le,
-    to_le,
-    8,
-    "The 64-bit little endian floating-point type."
+    "The 64-bit floating-point type, generic over its byte order."
 );
+
+macro_rules! create_float_alias {
+    ($alias:ident, $generic:ident, $order:ident, $doc:literal) => {
+        #[doc = $doc]
+        #[allow(non_camel_case_types)]
+        pub type $alias = $generic<$order>;
+    };
+}
+
+create_float_alias!(f32be, F32, BigEndian, "The 32-bit big endian floating-point type.");
+create_float_alias!(f64be, F64, BigEndian, "The 64-bit big endian floating-point type.");
+create_float_alias!(f32le, F32, LittleEndian, "The 32-bit little endian floating-point type.");
+create_float_alias!(f64le, F64, LittleEndian, "The 64-bit little endian floating-point type.");
+
+#[cfg(target_endian = "big")]
+type NativeEndian = BigEndian;
+#[cfg(target_endian = "little")]
+type NativeEndian = LittleEndian;
+
+/// Aliases for network byte order, which is always big endian.
+///
+/// These are equivalent to the `*be` aliases at the crate root, and exist so that protocol
+/// definitions can spell out their intent, e.g. `src_port: network_endian::u16`.
+pub mod network_endian {
+    use super::{BigEndian, F32, F64, I128, I16, I32, I64, U128, U16, U32, U64};
+
+    /// The 16-bit network byte order unsigned integer type.
+    #[allow(non_camel_case_types)]
+    pub type u16 = U16<BigEndian>;
+    /// The 32-bit network byte order unsigned integer type.
+    #[allow(non_camel_case_types)]
+    pub type u32 = U32<BigEndian>;
+    /// The 64-bit network byte order unsigned integer type.
+    #[allow(non_camel_case_types)]
+    pub type u64 = U64<BigEndian>;
+    /// The 128-bit network byte order unsigned integer type.
+    #[allow(non_camel_case_types)]
+    pub type u128 = U128<BigEndian>;
+
+    /// The 16-bit network byte order signed integer type.
+    #[allow(non_camel_case_types)]
+    pub type i16 = I16<BigEndian>;
+    /// The 32-bit network byte order signed integer type.
+    #[allow(non_camel_case_types)]
+    pub type i32 = I32<BigEndian>;
+    /// The 64-bit network byte order signed integer type.
+    #[allow(non_camel_case_types)]
+    pub type i64 = I64<BigEndian>;
+    /// The 128-bit network byte order signed integer type.
+    #[allow(non_camel_case_types)]
+    pub type i128 = I128<BigEndian>;
+
+    /// The 32-bit network byte order floating-point type.
+    #[allow(non_camel_case_types)]
+    pub type f32 = F32<BigEndian>;
+    /// The 64-bit network byte order floating-point type.
+    #[allow(non_camel_case_types)]
+    pub type f64 = F64<BigEndian>;
+}
+
+/// Aliases that resolve to the target's native byte order at compile time.
+///
+/// On a matching target, `new`/`get`/`set` compile down to no-ops, since no byte swap is
+/// required. Use these for formats that are specified as "native endian on disk", removing
+/// the need to `cfg`-switch between the `*be`/`*le` aliases by hand.
+pub mod native_endian {
+    use super::{NativeEndian, F32, F64, I128, I16, I32, I64, U128, U16, U32, U64};
+
+    /// The 16-bit native byte order unsigned integer type.
+    #[allow(non_camel_case_types)]
+    pub type u16 = U16<NativeEndian>;
+    /// The 32-bit native byte order unsigned integer type.
+    #[allow(non_camel_case_types)]
+    pub type u32 = U32<NativeEndian>;
+    /// The 64-bit native byte order unsigned integer type.
+    #[allow(non_camel_case_types)]
+    pub type u64 = U64<NativeEndian>;
+    /// The 128-bit native byte order unsigned integer type.
+    #[allow(non_camel_case_types)]
+    pub type u128 = U128<NativeEndian>;
+
+    /// The 16-bit native byte order signed integer type.
+    #[allow(non_camel_case_types)]
+    pub type i16 = I16<NativeEndian>;
+    /// The 32-bit native byte order signed integer type.
+    #[allow(non_camel_case_types)]
+    pub type i32 = I32<NativeEndian>;
+    /// The 64-bit native byte order signed integer type.
+    #[allow(non_camel_case_types)]
+    pub type i64 = I64<NativeEndian>;
+    /// The 128-bit native byte order signed integer type.
+    #[allow(non_camel_case_types)]
+    pub type i128 = I128<NativeEndian>;
+
+    /// The 32-bit native byte order floating-point type.
+    #[allow(non_camel_case_types)]
+    pub type f32 = F32<NativeEndian>;
+    /// The 64-bit native byte order floating-point type.
+    #[allow(non_camel_case_types)]
+    pub type f64 = F64<NativeEndian>;
+}
@@ -20,6 +20,17 @@ fn basic() {
         u16::from_be_bytes([0x12, 0x34])
     );
 
+    // check the rest of the to/from _bytes conversion family round-trips
+    assert_eq!(u32be::from_le_bytes([0x44, 0x33, 0x22, 0x11]).get(), 0x11223344);
+    assert_eq!(u32be::new(0x11223344).to_be_bytes(), [0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(u32le::new(0x11223344).to_le_bytes(), [0x44, 0x33, 0x22, 0x11]);
+    assert_eq!(
+        u32be::from_ne_bytes(u32be::new(0x11223344).to_ne_bytes()).get(),
+        0x11223344
+    );
+    assert_eq!(f32be::from_le_bytes(f32be::new(1.5).to_le_bytes()).get(), 1.5);
+    assert_eq!(f32le::from_be_bytes(f32le::new(1.5).to_be_bytes()).get(), 1.5);
+
     let mut data = {
         union BytesAsData {
             data: Data,
@@ -78,4 +89,23 @@ fn basic() {
             mem::transmute::<f32le, u32>(f32le::new(f32::NEG_INFINITY))
         );
     }
+
+    // check ordering, including the conversion-free fast path for unsigned big endian
+    assert!(u32be::new(1) < u32be::new(2));
+    assert!(u32le::new(1) < u32le::new(2));
+    assert!(data.a > u32be::new(0x11223343));
+
+    // check swap_bytes (reinterpret, stored bytes unchanged) vs to_opposite_endian (stored
+    // bytes reversed, numeric value unchanged), which are easy to mix up
+    let be = u32be::new(0x1122_3344);
+    let swapped = be.swap_bytes();
+    assert_eq!(swapped.get(), 0x4433_2211);
+    unsafe {
+        assert_eq!(
+            mem::transmute::<u32be, [u8; 4]>(be),
+            mem::transmute::<u32le, [u8; 4]>(swapped)
+        );
+    }
+    let opposite = be.to_opposite_endian();
+    assert_eq!(opposite.get(), 0x1122_3344);
 }
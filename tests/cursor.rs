@@ -0,0 +1,25 @@
+use yael::cursor::{Reader, Writer};
+
+#[test]
+fn cursor() {
+    let bytes = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
+    let mut reader = Reader::new(&bytes);
+
+    assert_eq!(reader.read_u16be(), Some(0x1122));
+    assert_eq!(reader.read_u16le(), Some(0x4433));
+    assert_eq!(reader.remaining(), 2);
+    assert_eq!(reader.read_u32be(), None); // only 2 bytes left
+    assert_eq!(reader.read_u16be(), Some(0x5566));
+    assert!(reader.is_empty());
+    assert_eq!(reader.read_u8(), None);
+
+    let mut buf = [0u8; 6];
+    let mut writer = Writer::new(&mut buf);
+    assert!(writer.write_u16be(0x1122));
+    assert!(writer.write_u16le(0x4433));
+    assert_eq!(writer.remaining(), 2);
+    assert!(!writer.write_u32be(0)); // only 2 bytes left
+    assert!(writer.write_u16be(0x5566));
+    assert!(writer.is_full());
+    assert_eq!(buf, bytes);
+}